@@ -1,16 +1,46 @@
 use std::io::{Stdout, Write};
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
+use crossbeam_channel::Receiver;
 use crossterm::cursor::{self, MoveTo, MoveToColumn};
 use crossterm::event::KeyModifiers;
 use crossterm::style::{style, Print, Stylize};
 use crossterm::terminal::{Clear, ClearType, ScrollDown, ScrollUp};
 use crossterm::{
-    event::{read, Event, KeyCode},
+    event::{Event, KeyCode},
     terminal::{disable_raw_mode, enable_raw_mode},
 };
 use crossterm::{ExecutableCommand, QueueableCommand};
-use unicode_width::UnicodeWidthChar;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::document::Document;
+
+mod document;
+
+// Polls crossterm for input/resize events on a background thread and
+// forwards them over a channel. This decouples event reading from
+// rendering, so the main loop can select between incoming events and a
+// tick interval instead of blocking on `read()`.
+fn spawn_event_reader() -> Receiver<Event> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    std::thread::spawn(move || loop {
+        match crossterm::event::poll(Duration::from_millis(50)) {
+            Ok(true) => match crossterm::event::read() {
+                Ok(event) => {
+                    if tx.send(event).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            },
+            Ok(false) => continue,
+            Err(_) => break,
+        }
+    });
+    rx
+}
 
 // Represents the final state of the editor when exiting
 pub struct EditorResult {
@@ -18,42 +48,100 @@ pub struct EditorResult {
     pub content: String, // The final contents of the file
 }
 
+// Number of rows reserved at the bottom of the screen for the status bar
+// and the transient message bar.
+const BOTTOM_BAR_ROWS: usize = 2;
+
+// A single undoable edit: `removed` (the text that used to sit at `offset`)
+// was replaced by `inserted`. Reapplying the op forward redoes the edit;
+// swapping the two and reinserting reverts it. Cursor positions before and
+// after the edit are captured so undo/redo can restore them exactly.
+struct EditOp {
+    offset: usize,
+    removed: String,
+    inserted: String,
+    cursor_before: (usize, usize),
+    cursor_after: (usize, usize),
+    coalesce_id: u64,
+}
+
 // Main editor struct that handles the editing functionality
 pub struct SkullEditor {
-    lines: Vec<Vec<char>>, // Each line is stored as a vector of characters
-    cursor_column: usize,  // Current cursor position within the line
-    cursor_line: usize,    // Current line number
+    document: Document,   // Rope-backed text buffer
+    cursor_column: usize, // Current cursor position within the line
+    cursor_line: usize,   // Current line number
     view_pos: usize,
+    col_offset: usize, // Horizontal scroll offset, in rendered columns
     current_view_height: usize,
-    stdout: Stdout, // Handle to standard output for terminal manipulation
+    filename: String,       // Path of the file being edited, shown in the status bar
+    dirty: bool,            // Whether the document has unsaved changes
+    status_message: String, // Transient message shown below the status bar
+    status_message_expiry: Option<Instant>, // When the message bar should clear itself
+    undo_stack: Vec<EditOp>,
+    redo_stack: Vec<EditOp>,
+    // Bumped on cursor movement, Enter, save, and undo/redo so that unrelated
+    // edits never coalesce into the same undo step.
+    coalesce_id: u64,
+    clipboard: Option<String>,         // Last line copied/cut with Ctrl+C/Ctrl+X
+    search_match: Option<Range<usize>>, // Char offset range of the active search match, if any
+    stdout: Stdout,                    // Handle to standard output for terminal manipulation
 }
 
 impl SkullEditor {
-    // Creates a new editor instance from input string, splitting it into lines
-    pub fn new(input: String) -> Self {
-        let mut lines: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-        // Ensure there's at least one line, even if input is empty
-        if lines.len() == 0 {
-            lines.push(Vec::new());
-        }
+    // Creates a new editor instance from a file path and its contents
+    pub fn new(filename: String, input: String) -> Self {
         Self {
-            lines,
+            document: Document::new(&input),
             cursor_column: 0,
             cursor_line: 0,
             view_pos: 0,
+            col_offset: 0,
             stdout: std::io::stdout(),
             current_view_height: 0,
+            filename,
+            dirty: false,
+            status_message: String::new(),
+            status_message_expiry: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            coalesce_id: 0,
+            clipboard: None,
+            search_match: None,
         }
     }
 
+    // Replaces the transient message shown in the message bar. Non-empty
+    // messages expire on their own after a few seconds via `on_tick`.
+    fn set_status_message(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.status_message_expiry =
+            (!message.is_empty()).then(|| Instant::now() + Duration::from_secs(3));
+        self.status_message = message;
+    }
+
+    // Clears the status message once it has expired; called on every tick
+    fn on_tick(&mut self) -> Result<()> {
+        if self.status_message_expiry.is_some_and(|expiry| Instant::now() >= expiry) {
+            self.status_message.clear();
+            self.status_message_expiry = None;
+            self.redraw()?;
+        }
+        Ok(())
+    }
+
     // Returns total number of lines in the editor
     fn get_height(&self) -> usize {
-        self.lines.len()
+        self.document.len_lines()
     }
 
     // Returns the length of the current line
     fn get_width(&mut self) -> usize {
-        self.lines[self.cursor_line].len()
+        self.document.line_len_chars(self.cursor_line)
+    }
+
+    // Char offset of the cursor within the rope
+    fn cursor_char_offset(&self) -> usize {
+        self.document.line_to_char(self.cursor_line) + self.cursor_column
     }
 
     fn char_width(c: char) -> usize {
@@ -64,9 +152,26 @@ impl SkullEditor {
         }
     }
 
+    // Truncates `s` to at most `max_width` display columns, at a char
+    // boundary, rather than a byte offset (which can land mid-codepoint for
+    // non-ASCII text).
+    fn truncate_to_width(s: &str, max_width: usize) -> String {
+        let mut result = String::new();
+        let mut width = 0;
+        for c in s.chars() {
+            let char_width = SkullEditor::char_width(c);
+            if width + char_width > max_width {
+                break;
+            }
+            width += char_width;
+            result.push(c);
+        }
+        result
+    }
+
     fn get_cursor_offset(&self) -> usize {
         let mut real_column = 0;
-        for &c in self.lines[self.cursor_line][..self.cursor_column].iter() {
+        for c in self.document.line_chars(self.cursor_line).take(self.cursor_column) {
             real_column += SkullEditor::char_width(c);
         }
         real_column
@@ -75,7 +180,7 @@ impl SkullEditor {
     fn offset_to_cursor(&self, offset: usize) -> usize {
         let mut real_column = 0;
         let mut cursor_column = 0;
-        for &c in self.lines[self.cursor_line].iter() {
+        for c in self.document.line_chars(self.cursor_line) {
             let width = SkullEditor::char_width(c);
             real_column += width;
             if real_column > offset {
@@ -86,8 +191,14 @@ impl SkullEditor {
         cursor_column
     }
 
+    // Breaks the undo coalescing chain so the next edit starts a fresh step
+    fn break_coalescing(&mut self) {
+        self.coalesce_id = self.coalesce_id.wrapping_add(1);
+    }
+
     // Moves cursor left, wrapping to previous line if at start of line
     fn move_cursor_left(&mut self) {
+        self.break_coalescing();
         if self.cursor_column > 0 {
             self.cursor_column -= 1;
         } else if self.cursor_line > 0 {
@@ -98,6 +209,7 @@ impl SkullEditor {
 
     // Moves cursor right, wrapping to next line if at end of line
     fn move_cursor_right(&mut self) {
+        self.break_coalescing();
         if self.cursor_column < self.get_width() {
             self.cursor_column += 1;
         } else if self.cursor_line + 1 < self.get_height() {
@@ -108,6 +220,7 @@ impl SkullEditor {
 
     // Moves cursor up one line, adjusting column position if necessary
     fn move_cursor_up(&mut self) {
+        self.break_coalescing();
         if self.cursor_line > 0 {
             let real_offset = self.get_cursor_offset();
             self.cursor_line -= 1;
@@ -119,6 +232,7 @@ impl SkullEditor {
 
     // Moves cursor down one line, adjusting column position if necessary
     fn move_cursor_down(&mut self) {
+        self.break_coalescing();
         if self.cursor_line + 1 < self.get_height() {
             let real_offset = self.get_cursor_offset();
             self.cursor_line += 1;
@@ -128,41 +242,425 @@ impl SkullEditor {
         }
     }
 
+    // Whether `c` is part of a "word" for word-motion purposes; anything
+    // else that isn't whitespace (punctuation) forms its own single-char-class group
+    fn is_word_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    // Classifies a char into word / punctuation / whitespace so word motion
+    // can tell where one group ends and the next begins
+    fn char_class(c: char) -> u8 {
+        if c.is_whitespace() {
+            0
+        } else if SkullEditor::is_word_char(c) {
+            1
+        } else {
+            2
+        }
+    }
+
+    // Finds the next word boundary from (line, column) in the given
+    // direction: skip whitespace, then skip the run of same-class chars that
+    // follows, crossing line boundaries at the edge of a line. Shared by
+    // word-wise cursor motion and word-wise deletion.
+    //
+    // The current line's chars are collected once per line crossed (rather
+    // than re-walking the rope from the start of the line on every step, as
+    // a naive `nth(column)` would), so a scan across a long line stays O(line
+    // length) instead of O(line length^2).
+    fn next_word_boundary(&self, mut line: usize, mut column: usize, forward: bool) -> (usize, usize) {
+        let mut chars: Vec<char> = self.document.line_chars(line).collect();
+        if forward {
+            loop {
+                match chars.get(column) {
+                    Some(&c) if SkullEditor::char_class(c) == 0 => column += 1,
+                    Some(_) => break,
+                    None if line + 1 < self.get_height() => {
+                        line += 1;
+                        column = 0;
+                        chars = self.document.line_chars(line).collect();
+                    }
+                    None => return (line, column),
+                }
+            }
+            if let Some(&c) = chars.get(column) {
+                let class = SkullEditor::char_class(c);
+                while chars.get(column).map(|&c| SkullEditor::char_class(c)) == Some(class) {
+                    column += 1;
+                }
+            }
+        } else {
+            loop {
+                if column == 0 {
+                    if line == 0 {
+                        return (line, column);
+                    }
+                    line -= 1;
+                    chars = self.document.line_chars(line).collect();
+                    column = chars.len();
+                    continue;
+                }
+                match chars.get(column - 1) {
+                    Some(&c) if SkullEditor::char_class(c) == 0 => column -= 1,
+                    _ => break,
+                }
+            }
+            if column > 0 {
+                let class = chars.get(column - 1).map(|&c| SkullEditor::char_class(c));
+                while column > 0 && chars.get(column - 1).map(|&c| SkullEditor::char_class(c)) == class {
+                    column -= 1;
+                }
+            }
+        }
+        (line, column)
+    }
+
+    // Moves the cursor to the previous word boundary
+    fn move_cursor_word_left(&mut self) {
+        self.break_coalescing();
+        (self.cursor_line, self.cursor_column) =
+            self.next_word_boundary(self.cursor_line, self.cursor_column, false);
+    }
+
+    // Moves the cursor to the next word boundary
+    fn move_cursor_word_right(&mut self) {
+        self.break_coalescing();
+        (self.cursor_line, self.cursor_column) =
+            self.next_word_boundary(self.cursor_line, self.cursor_column, true);
+    }
+
+    // Removes the text between two (line, column) positions as a single
+    // undo step, leaving the cursor at `from`
+    fn delete_range(&mut self, from: (usize, usize), to: (usize, usize), cursor_before: (usize, usize)) {
+        let from_offset = self.document.line_to_char(from.0) + from.1;
+        let to_offset = self.document.line_to_char(to.0) + to.1;
+        if from_offset >= to_offset {
+            return;
+        }
+        self.break_coalescing();
+        let removed = self.document.slice(from_offset..to_offset);
+        self.document.remove(from_offset..to_offset);
+        self.cursor_line = from.0;
+        self.cursor_column = from.1;
+        self.dirty = true;
+        self.redo_stack.clear();
+        self.undo_stack.push(EditOp {
+            offset: from_offset,
+            removed,
+            inserted: String::new(),
+            cursor_before,
+            cursor_after: from,
+            coalesce_id: self.coalesce_id,
+        });
+        self.break_coalescing();
+    }
+
+    // Ctrl+Backspace: erases from the cursor back to the previous word boundary
+    fn erase_word_backward(&mut self) {
+        let cursor_before = (self.cursor_line, self.cursor_column);
+        let boundary = self.next_word_boundary(self.cursor_line, self.cursor_column, false);
+        self.delete_range(boundary, cursor_before, cursor_before);
+    }
+
+    // Ctrl+Delete: erases from the cursor forward to the next word boundary
+    fn erase_word_forward(&mut self) {
+        let cursor_before = (self.cursor_line, self.cursor_column);
+        let boundary = self.next_word_boundary(self.cursor_line, self.cursor_column, true);
+        self.delete_range(cursor_before, boundary, cursor_before);
+    }
+
+    // Ctrl+C: copies the current line into the clipboard register
+    fn copy_line(&mut self) {
+        self.clipboard = Some(self.document.line_chars(self.cursor_line).collect());
+    }
+
+    // Ctrl+X: cuts the current line into the clipboard register, leaving a
+    // single empty line behind if it was the only line in the document
+    fn cut_line(&mut self) {
+        let height = self.get_height();
+        let cursor_before = (self.cursor_line, self.cursor_column);
+        let line_start = self.document.line_to_char(self.cursor_line);
+        let line_len = self.document.line_len_chars(self.cursor_line);
+        self.clipboard = Some(self.document.slice(line_start..line_start + line_len));
+
+        let (remove_range, cursor_after) = if height == 1 {
+            (line_start..line_start + line_len, (0, 0))
+        } else if self.cursor_line + 1 < height {
+            (
+                line_start..self.document.line_to_char(self.cursor_line + 1),
+                (self.cursor_line, 0),
+            )
+        } else {
+            let prev_line_end = self.document.line_to_char(self.cursor_line - 1)
+                + self.document.line_len_chars(self.cursor_line - 1);
+            (
+                prev_line_end..line_start + line_len,
+                (self.cursor_line - 1, 0),
+            )
+        };
+
+        self.break_coalescing();
+        let removed = self.document.slice(remove_range.clone());
+        self.document.remove(remove_range.clone());
+        (self.cursor_line, self.cursor_column) = cursor_after;
+        self.dirty = true;
+        self.redo_stack.clear();
+        self.undo_stack.push(EditOp {
+            offset: remove_range.start,
+            removed,
+            inserted: String::new(),
+            cursor_before,
+            cursor_after,
+            coalesce_id: self.coalesce_id,
+        });
+        self.break_coalescing();
+    }
+
+    // Ctrl+V: pastes the clipboard register as a fresh line above the
+    // cursor line and moves the cursor onto it
+    fn paste_line(&mut self) {
+        let Some(line) = self.clipboard.clone() else {
+            return;
+        };
+        self.break_coalescing();
+        let cursor_before = (self.cursor_line, self.cursor_column);
+        let offset = self.document.line_to_char(self.cursor_line);
+        let mut inserted = line;
+        inserted.push('\n');
+        for (i, c) in inserted.chars().enumerate() {
+            self.document.insert_char(offset + i, c);
+        }
+        self.cursor_column = 0;
+        self.dirty = true;
+        self.redo_stack.clear();
+        self.undo_stack.push(EditOp {
+            offset,
+            removed: String::new(),
+            inserted,
+            cursor_before,
+            cursor_after: (self.cursor_line, self.cursor_column),
+            coalesce_id: self.coalesce_id,
+        });
+        self.break_coalescing();
+    }
+
+    // Runs a search for `query` from `from_offset`, moving the cursor to the
+    // match and recording it in `search_match` for redraw to highlight
+    fn run_search(&mut self, query: &str, from_offset: usize, forward: bool) {
+        let Some(range) = self.document.find(query, from_offset, forward) else {
+            self.search_match = None;
+            return;
+        };
+        self.cursor_line = self.document.char_to_line(range.start);
+        self.cursor_column = range.start - self.document.line_to_char(self.cursor_line);
+        self.search_match = Some(range);
+    }
+
+    // Jumps to the next/previous match of `query` relative to the current one
+    fn search_step(&mut self, query: &str, forward: bool) {
+        let from = match &self.search_match {
+            Some(range) if forward => range.end,
+            Some(range) => range.start,
+            None => self.cursor_char_offset(),
+        };
+        self.run_search(query, from, forward);
+    }
+
+    // Ctrl+F: incremental search with live highlighting. Reads keystrokes
+    // into a query shown in the message bar; Enter confirms at the match,
+    // Esc restores the pre-search cursor and scroll position.
+    fn search(&mut self, events: &Receiver<Event>) -> Result<()> {
+        let origin_cursor = (self.cursor_line, self.cursor_column);
+        let origin_offset = self.cursor_char_offset();
+        let origin_view_pos = self.view_pos;
+        let mut query = String::new();
+        self.search_match = None;
+
+        loop {
+            self.set_status_message(format!("Find: {query}"));
+            self.redraw()?;
+
+            let Event::Key(key_event) = events.recv()? else {
+                continue;
+            };
+            match key_event.code {
+                KeyCode::Esc => {
+                    (self.cursor_line, self.cursor_column) = origin_cursor;
+                    self.view_pos = origin_view_pos;
+                    self.search_match = None;
+                    break;
+                }
+                KeyCode::Enter => break,
+                KeyCode::Backspace => {
+                    query.pop();
+                    self.run_search(&query, origin_offset, true);
+                }
+                KeyCode::Down => self.search_step(&query, true),
+                KeyCode::Up => self.search_step(&query, false),
+                KeyCode::Char('n') if key_event.modifiers == KeyModifiers::CONTROL => {
+                    self.search_step(&query, true)
+                }
+                KeyCode::Char('p') if key_event.modifiers == KeyModifiers::CONTROL => {
+                    self.search_step(&query, false)
+                }
+                KeyCode::Char(c)
+                    if key_event.modifiers & !KeyModifiers::SHIFT == KeyModifiers::NONE =>
+                {
+                    query.push(c);
+                    self.run_search(&query, origin_offset, true);
+                }
+                _ => {}
+            }
+        }
+
+        self.search_match = None;
+        self.set_status_message(String::new());
+        self.break_coalescing();
+        Ok(())
+    }
+
+    // Pushes an insertion onto the undo stack, coalescing with the previous
+    // op if it's an adjacent insertion from the same coalescing chain
+    fn push_insert(&mut self, offset: usize, c: char, cursor_before: (usize, usize)) {
+        let cursor_after = (self.cursor_line, self.cursor_column);
+        if let Some(last) = self.undo_stack.last_mut() {
+            if last.coalesce_id == self.coalesce_id
+                && last.removed.is_empty()
+                && last.offset + last.inserted.chars().count() == offset
+            {
+                last.inserted.push(c);
+                last.cursor_after = cursor_after;
+                return;
+            }
+        }
+        self.undo_stack.push(EditOp {
+            offset,
+            removed: String::new(),
+            inserted: c.to_string(),
+            cursor_before,
+            cursor_after,
+            coalesce_id: self.coalesce_id,
+        });
+    }
+
+    // Pushes a deletion onto the undo stack, coalescing with the previous op
+    // if it's an adjacent deletion (e.g. a run of backspaces) from the same
+    // coalescing chain
+    fn push_delete(&mut self, offset: usize, c: char, cursor_before: (usize, usize)) {
+        let cursor_after = (self.cursor_line, self.cursor_column);
+        if let Some(last) = self.undo_stack.last_mut() {
+            if last.coalesce_id == self.coalesce_id && last.inserted.is_empty() && last.offset == offset + 1 {
+                last.removed.insert(0, c);
+                last.offset = offset;
+                last.cursor_after = cursor_after;
+                return;
+            }
+        }
+        self.undo_stack.push(EditOp {
+            offset,
+            removed: c.to_string(),
+            inserted: String::new(),
+            cursor_before,
+            cursor_after,
+            coalesce_id: self.coalesce_id,
+        });
+    }
+
     // Inserts a character at the current cursor position
     fn add_character(&mut self, c: char) {
-        self.lines[self.cursor_line].insert(self.cursor_column, c);
+        let offset = self.cursor_char_offset();
+        let cursor_before = (self.cursor_line, self.cursor_column);
+        self.document.insert_char(offset, c);
         self.cursor_column += 1;
+        self.dirty = true;
+        self.redo_stack.clear();
+        self.push_insert(offset, c, cursor_before);
     }
 
     // Handles Enter key press - splits the current line at cursor position
     fn new_line(&mut self) {
-        // Split current line at cursor position, taking remainder to new line
-        let new_line = self.lines[self.cursor_line].split_off(self.cursor_column);
+        self.break_coalescing();
+        let offset = self.cursor_char_offset();
+        let cursor_before = (self.cursor_line, self.cursor_column);
+        self.document.insert_char(offset, '\n');
         self.cursor_line += 1;
         self.cursor_column = 0;
-        self.lines.insert(self.cursor_line, new_line);
+        self.dirty = true;
+        self.redo_stack.clear();
+        self.push_insert(offset, '\n', cursor_before);
+        self.break_coalescing();
     }
 
     // Handles backspace - removes character before cursor
     fn erase_character(&mut self) {
         if self.cursor_column > 0 {
+            let offset = self.cursor_char_offset();
+            let removed = self
+                .document
+                .line_chars(self.cursor_line)
+                .nth(self.cursor_column - 1)
+                .expect("cursor_column > 0 implies a preceding char on this line");
+            let cursor_before = (self.cursor_line, self.cursor_column);
+            self.document.remove(offset - 1..offset);
             self.cursor_column -= 1;
-            self.lines[self.cursor_line].remove(self.cursor_column);
+            self.dirty = true;
+            self.redo_stack.clear();
+            self.push_delete(offset - 1, removed, cursor_before);
         } else if self.cursor_line > 0 {
-            // If at start of line, join with previous line
-            let removed_line = self.lines.remove(self.cursor_line);
-            self.cursor_line -= 1;
-            let current_line = &mut self.lines[self.cursor_line];
-            current_line.extend_from_slice(&removed_line);
-            self.cursor_column = current_line.len();
+            // If at start of line, join with previous line by removing its full
+            // terminator, which may be more than one char (e.g. CRLF), rather
+            // than assuming a fixed 1-char width.
+            let cursor_before = (self.cursor_line, self.cursor_column);
+            let prev_width = self.document.line_len_chars(self.cursor_line - 1);
+            self.delete_range((self.cursor_line - 1, prev_width), (self.cursor_line, 0), cursor_before);
+        }
+    }
+
+    // Reverts the most recent undo step, if any
+    fn undo(&mut self) {
+        let Some(op) = self.undo_stack.pop() else {
+            return;
+        };
+        if !op.inserted.is_empty() {
+            let len = op.inserted.chars().count();
+            self.document.remove(op.offset..op.offset + len);
+        }
+        for (i, c) in op.removed.chars().enumerate() {
+            self.document.insert_char(op.offset + i, c);
+        }
+        (self.cursor_line, self.cursor_column) = op.cursor_before;
+        self.dirty = true;
+        self.break_coalescing();
+        self.redo_stack.push(op);
+    }
+
+    // Reapplies the most recently undone step, if any
+    fn redo(&mut self) {
+        let Some(op) = self.redo_stack.pop() else {
+            return;
+        };
+        if !op.removed.is_empty() {
+            let len = op.removed.chars().count();
+            self.document.remove(op.offset..op.offset + len);
+        }
+        for (i, c) in op.inserted.chars().enumerate() {
+            self.document.insert_char(op.offset + i, c);
         }
+        (self.cursor_line, self.cursor_column) = op.cursor_after;
+        self.dirty = true;
+        self.break_coalescing();
+        self.undo_stack.push(op);
     }
 
     // Redraws the entire editor contents with line numbers
     fn redraw(&mut self) -> Result<()> {
-        let (_, height) = crossterm::terminal::size()?;
+        let (term_width, height) = crossterm::terminal::size()?;
         let doc_height = self.get_height();
-        let view_height = (height as usize).min(doc_height).max(1);
+        let view_height = (height as usize)
+            .saturating_sub(BOTTOM_BAR_ROWS)
+            .min(doc_height)
+            .max(1);
 
         if view_height != self.current_view_height {
             if view_height > self.current_view_height {
@@ -194,12 +692,21 @@ impl SkullEditor {
 
         // Calculate width needed for line numbers
         let line_number_offset = self.get_height().ilog10() as usize + 1;
+        let gutter_width = line_number_offset + 2;
+        let text_width = (term_width as usize).saturating_sub(gutter_width).max(1);
+
+        // Scroll horizontally so the cursor's rendered column stays on-screen,
+        // mirroring the vertical view_pos adjustment above
+        let cursor_render_col = self.get_cursor_offset();
+        if self.col_offset > cursor_render_col {
+            self.col_offset = cursor_render_col;
+        }
+        if cursor_render_col - self.col_offset >= text_width {
+            self.col_offset = cursor_render_col - text_width + 1;
+        }
 
-        // Draw each line with line number
-        for (i, line) in self.lines[self.view_pos..self.view_pos + view_height]
-            .iter()
-            .enumerate()
-        {
+        // Draw each visible line, sliced straight out of the rope, with line number
+        for i in 0..view_height {
             if i > 0 {
                 self.stdout.queue(Print("\r\n"))?;
             }
@@ -212,27 +719,86 @@ impl SkullEditor {
                 .queue(Print(style(line_number).dark_grey().dim()))?
                 .queue(Print(' '))?;
 
-            // Draw the line content, handling tabs specially
-            for &c in line.iter() {
+            // Draw the line content within [col_offset, col_offset + text_width),
+            // handling tabs specially and reverse-videoing the active search
+            // match. A char that only partially overlaps the window is
+            // skipped entirely rather than drawn cut off.
+            let line_start_offset = self.document.line_to_char(self.view_pos + i);
+            let mut render_col = 0;
+            for (col, c) in self.document.line_chars(self.view_pos + i).enumerate() {
+                let width = SkullEditor::char_width(c);
+                let start = render_col;
+                render_col += width;
+                if start >= self.col_offset + text_width {
+                    break;
+                }
+                if start < self.col_offset || render_col > self.col_offset + text_width {
+                    continue;
+                }
+
+                let highlighted = self
+                    .search_match
+                    .as_ref()
+                    .is_some_and(|range| range.contains(&(line_start_offset + col)));
                 if c == '\t' {
-                    self.stdout.queue(Print(style("    ").dark_grey().dim()))?;
+                    let tab = style("    ").dark_grey().dim();
+                    self.stdout
+                        .queue(Print(if highlighted { tab.negative() } else { tab }))?;
                     continue;
                 }
-                let mut bytes = [0u8; 4];
-                let utf8 = c.encode_utf8(&mut bytes);
-                self.stdout.write_all(utf8.as_bytes())?;
+                if highlighted {
+                    self.stdout.queue(Print(style(c).negative()))?;
+                } else {
+                    let mut bytes = [0u8; 4];
+                    let utf8 = c.encode_utf8(&mut bytes);
+                    self.stdout.write_all(utf8.as_bytes())?;
+                }
             }
         }
 
-        // Calculate and set actual cursor position, accounting for tabs
+        self.draw_status_bar(view_height as u16, doc_height)?;
+
+        // Calculate and set actual cursor position, accounting for tabs and
+        // the horizontal scroll offset
         self.stdout.queue(MoveTo(
-            (line_number_offset + 2 + self.get_cursor_offset()) as u16,
+            (gutter_width + cursor_render_col - self.col_offset) as u16,
             (self.cursor_line - self.view_pos) as u16,
         ))?;
         self.stdout.flush()?;
         Ok(())
     }
 
+    // Draws the inverted-color status line and the message line beneath it,
+    // directly below the last visible document row.
+    fn draw_status_bar(&mut self, doc_rows: u16, doc_height: usize) -> Result<()> {
+        let (width, _) = crossterm::terminal::size()?;
+        let width = width as usize;
+
+        let dirty_marker = if self.dirty { "[modified]" } else { "" };
+        let left = format!(" {} {}", self.filename, dirty_marker);
+        let right = format!(
+            "{}:{} | {} lines ",
+            self.cursor_line + 1,
+            self.cursor_column + 1,
+            doc_height
+        );
+        let padding = width.saturating_sub(left.width() + right.width());
+        let status = format!("{left}{}{right}", " ".repeat(padding));
+        let status = SkullEditor::truncate_to_width(&status, width);
+
+        self.stdout
+            .queue(MoveTo(0, doc_rows))?
+            .queue(Clear(ClearType::CurrentLine))?
+            .queue(Print(style(status).negative()))?;
+
+        self.stdout
+            .queue(MoveTo(0, doc_rows + 1))?
+            .queue(Clear(ClearType::CurrentLine))?
+            .queue(Print(&self.status_message))?;
+
+        Ok(())
+    }
+
     // Main editor loop that handles user input
     pub fn run(mut self) -> Result<EditorResult> {
         enable_raw_mode()?; // Enable raw mode for direct terminal input
@@ -240,54 +806,91 @@ impl SkullEditor {
         self.redraw()?;
 
         let mut save = false;
+        let events = spawn_event_reader();
+        let ticks = crossbeam_channel::tick(Duration::from_millis(250));
 
-        // Main event loop
+        // Main event loop: select between incoming input/resize events and a
+        // tick interval, so the status message can expire without blocking
+        // on input
         loop {
-            let event = read()?;
-
-            if let Event::Key(key_event) = event {
-                match key_event.code {
-                    KeyCode::Backspace => self.erase_character(),
-                    KeyCode::Enter => self.new_line(),
-                    KeyCode::Left => self.move_cursor_left(),
-                    KeyCode::Right => self.move_cursor_right(),
-                    KeyCode::Up => self.move_cursor_up(),
-                    KeyCode::Down => self.move_cursor_down(),
-                    // Ctrl+H acts as backspace
-                    KeyCode::Char('h') if key_event.modifiers == KeyModifiers::CONTROL => {
-                        self.erase_character()
+            crossbeam_channel::select! {
+                recv(events) -> event => {
+                    let event = event?;
+
+                    if let Event::Key(key_event) = event {
+                        match key_event.code {
+                            // Ctrl+Left/Right jump by word; Ctrl+Backspace/Delete erase a word
+                            KeyCode::Left if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.move_cursor_word_left()
+                            }
+                            KeyCode::Right if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.move_cursor_word_right()
+                            }
+                            KeyCode::Backspace if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.erase_word_backward()
+                            }
+                            KeyCode::Delete if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.erase_word_forward()
+                            }
+                            KeyCode::Backspace => self.erase_character(),
+                            KeyCode::Enter => self.new_line(),
+                            KeyCode::Left => self.move_cursor_left(),
+                            KeyCode::Right => self.move_cursor_right(),
+                            KeyCode::Up => self.move_cursor_up(),
+                            KeyCode::Down => self.move_cursor_down(),
+                            // Ctrl+H acts as backspace
+                            KeyCode::Char('h') if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.erase_character()
+                            }
+                            // Ctrl+S triggers save
+                            KeyCode::Char('s') if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.break_coalescing();
+                                save = true;
+                                break;
+                            }
+                            // Ctrl+Z undoes the last edit
+                            KeyCode::Char('z') if key_event.modifiers == KeyModifiers::CONTROL => self.undo(),
+                            // Ctrl+Y (or Ctrl+Shift+Z) redoes the last undone edit
+                            KeyCode::Char('y') if key_event.modifiers == KeyModifiers::CONTROL => self.redo(),
+                            KeyCode::Char('z')
+                                if key_event.modifiers == KeyModifiers::CONTROL | KeyModifiers::SHIFT =>
+                            {
+                                self.redo()
+                            }
+                            // Ctrl+C/X/V: copy, cut, and paste whole lines
+                            KeyCode::Char('c') if key_event.modifiers == KeyModifiers::CONTROL => self.copy_line(),
+                            KeyCode::Char('x') if key_event.modifiers == KeyModifiers::CONTROL => self.cut_line(),
+                            KeyCode::Char('v') if key_event.modifiers == KeyModifiers::CONTROL => self.paste_line(),
+                            // Ctrl+F enters incremental search mode
+                            KeyCode::Char('f') if key_event.modifiers == KeyModifiers::CONTROL => {
+                                self.search(&events)?
+                            }
+                            KeyCode::Char(c) => self.add_character(c),
+                            KeyCode::Tab => self.add_character('\t'),
+                            KeyCode::Esc => {
+                                break;
+                            }
+                            _ => {}
+                        }
                     }
-                    // Ctrl+S triggers save
-                    KeyCode::Char('s') if key_event.modifiers == KeyModifiers::CONTROL => {
-                        save = true;
-                        break;
-                    }
-                    KeyCode::Char(c) => self.add_character(c),
-                    KeyCode::Tab => self.add_character('\t'),
-                    KeyCode::Esc => {
-                        break;
+
+                    if matches!(event, Event::Resize(..) | Event::Key(..)) {
+                        self.redraw()?;
                     }
-                    _ => {}
                 }
-            }
-
-            if matches!(event, Event::Resize(..) | Event::Key(..)) {
-                self.redraw()?;
+                recv(ticks) -> _ => {
+                    self.on_tick()?;
+                }
             }
         }
 
-        // Clear screen when exiting
-        self.stdout
-            .queue(MoveTo(0, 0))?
-            .queue(Clear(ClearType::FromCursorDown))?
-            .flush()?;
-
-        // If not already saving, ask user if they want to save
+        // If not already saving, ask the user via the message bar instead of
+        // hijacking the whole screen
         if !save {
-            self.stdout
-                .execute(Print("Do you want to save a file?\r\nSelect y[es]/n[o]"))?;
+            self.set_status_message("Save changes? y[es]/n[o]");
+            self.redraw()?;
             loop {
-                let Event::Key(key_event) = read()? else {
+                let Event::Key(key_event) = events.recv()? else {
                     continue;
                 };
                 match key_event.code {
@@ -302,23 +905,17 @@ impl SkullEditor {
                     _ => {}
                 }
             }
-
-            self.stdout
-                .queue(MoveTo(0, 0))?
-                .queue(Clear(ClearType::FromCursorDown))?
-                .flush()?;
         }
 
+        // Clear screen when exiting
+        self.stdout
+            .queue(MoveTo(0, 0))?
+            .queue(Clear(ClearType::FromCursorDown))?
+            .flush()?;
+
         disable_raw_mode()?; // Restore terminal to normal mode
 
-        // Convert the editor contents back to a single string
-        let mut content = String::new();
-        for (i, line) in self.lines.into_iter().enumerate() {
-            if i > 0 {
-                content.push('\n');
-            }
-            content.extend(line);
-        }
+        let content = self.document.to_string();
         Ok(EditorResult { save, content })
     }
 }
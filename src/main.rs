@@ -16,7 +16,7 @@ fn main() -> Result<()> {
     let args = Args::parse();
     // Read file contents or use empty string if file doesn't exist
     let input = fs::read_to_string(&args.file_path).unwrap_or_default();
-    let result = SkullEditor::new(input).run()?;
+    let result = SkullEditor::new(args.file_path.clone(), input).run()?;
     if result.save {
         fs::write(&args.file_path, result.content)?;
     }